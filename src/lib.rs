@@ -12,6 +12,9 @@ use core::marker::PhantomData;
 pub struct Natural<T, Ref: ?Sized = str>(pub T, PhantomData<Ref>);
 
 pub type NaturalAscii<T> = Natural<T, [u8]>;
+pub type NaturalUnicode<T> = Natural<T, Unicode>;
+pub type NaturalVersion<T> = Natural<T, Version>;
+pub type NaturalPath<T> = Natural<T, PathBytes>;
 
 impl<T, Ref: ?Sized> Natural<T, Ref> {
     pub fn new(value: T) -> Self {
@@ -31,6 +34,24 @@ impl<T> NaturalAscii<T> {
     }
 }
 
+impl<T> NaturalUnicode<T> {
+    pub fn unicode(value: T) -> Self {
+        Self(value, PhantomData)
+    }
+}
+
+impl<T> NaturalVersion<T> {
+    pub fn version(value: T) -> Self {
+        Self(value, PhantomData)
+    }
+}
+
+impl<T> NaturalPath<T> {
+    pub fn path(value: T) -> Self {
+        Self(value, PhantomData)
+    }
+}
+
 impl<T: Clone, Ref: ?Sized> Clone for Natural<T, Ref> {
     #[inline]
     fn clone(&self) -> Self {
@@ -89,6 +110,12 @@ mod sealed {
 pub trait NaturalSortable: sealed::NaturalSortable {
     /// returns the [natural sort order](https://en.wikipedia.org/wiki/Natural_sort_order)
     /// note the bytes are interpreted as ascii when using `[u8]`
+    ///
+    /// ties between numerically equal digit runs are broken by leading-zero
+    /// count (fewer leading zeros sorts first), so the `str` and `[u8]` impls
+    /// stay consistent with byte equality: `"file1"` < `"file01"` < `"file001"`;
+    /// [`Unicode`] and [`Version`] (and [`PathBytes`] on Windows) compare
+    /// some differing byte strings as equal
     fn natural_cmp(&self, other: &Self) -> Ordering;
 }
 
@@ -106,6 +133,237 @@ impl NaturalSortable for [u8] {
     }
 }
 
+/// a `str` wrapper whose [`NaturalSortable`] impl decodes digit runs as
+/// `char`s instead of ASCII bytes (see [`NaturalUnicode`])
+///
+/// opt in to this with [`Natural::new`]/[`NaturalUnicode::unicode`] when the
+/// hot ASCII fast path used by `str`'s own impl isn't what you want; numerals
+/// from other scripts (full-width `\u{ff11}\u{ff12}\u{ff13}`, Arabic-Indic
+/// `\u{0661}\u{0662}\u{0663}`, ...) are compared by numeric magnitude rather
+/// than by code point, and a mixed-script run such as `1\u{ff12}3` is treated
+/// as a single numeric token
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct Unicode(str);
+
+impl Unicode {
+    #[inline(always)]
+    pub fn new(value: &str) -> &Self {
+        // Safety: `Unicode` is `#[repr(transparent)]` over `str`
+        unsafe { &*(value as *const str as *const Self) }
+    }
+}
+
+impl AsRef<Unicode> for str {
+    #[inline(always)]
+    fn as_ref(&self) -> &Unicode {
+        Unicode::new(self)
+    }
+}
+
+impl AsRef<Unicode> for Unicode {
+    #[inline(always)]
+    fn as_ref(&self) -> &Unicode {
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl AsRef<Unicode> for alloc::string::String {
+    #[inline(always)]
+    fn as_ref(&self) -> &Unicode {
+        Unicode::new(self.as_str())
+    }
+}
+
+impl sealed::NaturalSortable for Unicode {
+    fn eq(&self, other: &Self) -> bool {
+        cmp_unicode(&self.0, &other.0) == Ordering::Equal
+    }
+}
+
+impl NaturalSortable for Unicode {
+    #[inline(always)]
+    fn natural_cmp(&self, other: &Self) -> Ordering {
+        cmp_unicode(&self.0, &other.0)
+    }
+}
+
+/// a `str` wrapper whose [`NaturalSortable`] impl compares `.`/`-`/`_`
+/// separated components independently (see [`NaturalVersion`] and
+/// [`natural_cmp_version`])
+///
+/// handy for sorting crate names, module paths and import identifiers, e.g.
+/// `v1.9.0` before `v1.10.0` while keeping `serde`/`serde_json` grouped
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct Version(str);
+
+impl Version {
+    #[inline(always)]
+    pub fn new(value: &str) -> &Self {
+        // Safety: `Version` is `#[repr(transparent)]` over `str`
+        unsafe { &*(value as *const str as *const Self) }
+    }
+}
+
+impl AsRef<Version> for str {
+    #[inline(always)]
+    fn as_ref(&self) -> &Version {
+        Version::new(self)
+    }
+}
+
+impl AsRef<Version> for Version {
+    #[inline(always)]
+    fn as_ref(&self) -> &Version {
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl AsRef<Version> for alloc::string::String {
+    #[inline(always)]
+    fn as_ref(&self) -> &Version {
+        Version::new(self.as_str())
+    }
+}
+
+impl sealed::NaturalSortable for Version {
+    fn eq(&self, other: &Self) -> bool {
+        natural_cmp_version(&self.0, &other.0) == Ordering::Equal
+    }
+}
+
+impl NaturalSortable for Version {
+    #[inline(always)]
+    fn natural_cmp(&self, other: &Self) -> Ordering {
+        natural_cmp_version(&self.0, &other.0)
+    }
+}
+
+/// compares `a` and `b` the way version strings (or crate/module paths) are
+/// expected to sort: a leading `r#` raw-identifier prefix is stripped from
+/// each side, then both are split on `.`, `-` and `_` and compared
+/// component-by-component using [`natural_cmp`]'s digit-aware ordering,
+/// with a missing trailing component sorting before a present one
+/// ## Example
+/// ```
+/// # use natural_sort_rs::natural_cmp_version;
+/// # use std::cmp::Ordering;
+///
+/// assert_eq!(natural_cmp_version("v1.9.0", "v1.10.0"), Ordering::Less);
+/// assert_eq!(natural_cmp_version("serde", "serde_json"), Ordering::Less);
+/// ```
+pub fn natural_cmp_version(a: &str, b: &str) -> Ordering {
+    fn is_separator(c: char) -> bool {
+        matches!(c, '.' | '-' | '_')
+    }
+
+    let a = a.strip_prefix("r#").unwrap_or(a);
+    let b = b.strip_prefix("r#").unwrap_or(b);
+
+    let mut a_parts = a.split(is_separator);
+    let mut b_parts = b.split(is_separator);
+
+    loop {
+        let ord = match (a_parts.next(), b_parts.next()) {
+            (Some(a_part), Some(b_part)) => cmp_ascii(a_part.as_bytes(), b_part.as_bytes()),
+            (a_next, b_next) => return a_next.is_some().cmp(&b_next.is_some()),
+        };
+
+        if ord != Ordering::Equal {
+            return ord;
+        }
+    }
+}
+
+/// a `[u8]` wrapper whose [`NaturalSortable`] impl compares `/`-separated
+/// (and, on Windows, `\`-separated) path components independently, the way
+/// `ls`-style tools sort directory trees (see [`NaturalPath`] and
+/// [`natural_cmp_path`])
+///
+/// a shorter path prefix always sorts before its descendants, e.g.
+/// `a` < `a/b` < `a.txt`; the separator set is fixed to the build target
+/// (mirroring `std::path::MAIN_SEPARATOR`), not the bytes' origin platform
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct PathBytes([u8]);
+
+impl PathBytes {
+    #[inline(always)]
+    pub fn new(value: &[u8]) -> &Self {
+        // Safety: `PathBytes` is `#[repr(transparent)]` over `[u8]`
+        unsafe { &*(value as *const [u8] as *const Self) }
+    }
+}
+
+impl AsRef<PathBytes> for [u8] {
+    #[inline(always)]
+    fn as_ref(&self) -> &PathBytes {
+        PathBytes::new(self)
+    }
+}
+
+impl AsRef<PathBytes> for PathBytes {
+    #[inline(always)]
+    fn as_ref(&self) -> &PathBytes {
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl AsRef<PathBytes> for alloc::vec::Vec<u8> {
+    #[inline(always)]
+    fn as_ref(&self) -> &PathBytes {
+        PathBytes::new(self)
+    }
+}
+
+impl sealed::NaturalSortable for PathBytes {
+    fn eq(&self, other: &Self) -> bool {
+        natural_cmp_path(&self.0, &other.0) == Ordering::Equal
+    }
+}
+
+impl NaturalSortable for PathBytes {
+    #[inline(always)]
+    fn natural_cmp(&self, other: &Self) -> Ordering {
+        natural_cmp_path(&self.0, &other.0)
+    }
+}
+
+/// compares two `/`-separated (and, on Windows, `\`-separated) paths
+/// component-by-component using [`natural_cmp`]'s digit-aware ordering, so
+/// a shorter path prefix always sorts before its descendants, e.g.
+/// `a` < `a/b` < `a.txt`
+///
+/// operates over raw bytes (rather than `str`) so it stays usable without
+/// the `alloc` feature and without assuming the path is valid UTF-8
+pub fn natural_cmp_path(a: &[u8], b: &[u8]) -> Ordering {
+    fn is_separator(byte: u8) -> bool {
+        if cfg!(windows) {
+            byte == b'/' || byte == b'\\'
+        } else {
+            byte == b'/'
+        }
+    }
+
+    let mut a_parts = a.split(|&byte| is_separator(byte));
+    let mut b_parts = b.split(|&byte| is_separator(byte));
+
+    loop {
+        let ord = match (a_parts.next(), b_parts.next()) {
+            (Some(a_part), Some(b_part)) => cmp_ascii(a_part, b_part),
+            (a_next, b_next) => return a_next.is_some().cmp(&b_next.is_some()),
+        };
+
+        if ord != Ordering::Equal {
+            return ord;
+        }
+    }
+}
+
 pub trait NaturalSort<T>: sealed::NaturalSort {
     fn natural_sort_unstable<Ref: ?Sized + NaturalSortable>(&mut self)
     where
@@ -132,6 +390,11 @@ pub trait NaturalSort<T>: sealed::NaturalSort {
     where
         F: FnMut(&T) -> K,
         K: AsRef<Ref>;
+
+    #[cfg(feature = "alloc")]
+    fn natural_sort_paths(&mut self)
+    where
+        T: AsRef<[u8]>;
 }
 
 impl<T> NaturalSort<T> for [T] {
@@ -226,6 +489,26 @@ impl<T> NaturalSort<T> for [T] {
     {
         self.sort_by_cached_key(|x| Natural::new(f(x)))
     }
+
+    /// like sort but comparing keys path-component-by-component (splitting
+    /// on `/`, and also on `\` when built for Windows), so a directory
+    /// always sorts before its descendants
+    /// ## Example
+    /// ```
+    /// # use natural_sort_rs::NaturalSort;
+    ///
+    /// let mut paths = ["a.txt", "a", "a/b"];
+    ///
+    /// paths.natural_sort_paths();
+    /// assert_eq!(paths, ["a", "a/b", "a.txt"])
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn natural_sort_paths(&mut self)
+    where
+        T: AsRef<[u8]>,
+    {
+        self.sort_by_cached_key(|x| Natural::<_, PathBytes>::new(x.as_ref().to_vec()))
+    }
 }
 
 pub fn natural_cmp<Ref: ?Sized + NaturalSortable, T: ?Sized + AsRef<Ref>>(x: &T, y: &T) -> Ordering {
@@ -233,9 +516,16 @@ pub fn natural_cmp<Ref: ?Sized + NaturalSortable, T: ?Sized + AsRef<Ref>>(x: &T,
 }
 
 fn cmp_ascii(mut a: &[u8], mut b: &[u8]) -> Ordering {
+    // the first digit run whose leading-zero count differs (while its
+    // numeric value compares equal) locks in the final tiebreak; unlike a
+    // summed delta, this can't cancel back out across later runs, so e.g.
+    // "a01b1" and "a1b01" still compare unequal, while "file1" < "file01" <
+    // "file001" stays consistent with byte equality
+    let mut zero_tiebreak: Option<Ordering> = None;
+
     while let ([a_c, a_tail @ ..], [b_c, b_tail @ ..]) = (a, b) {
         let ord = match a_c.is_ascii_digit() && b_c.is_ascii_digit() {
-            true => cmp_digits(&mut a, &mut b),
+            true => cmp_digits(&mut a, &mut b, &mut zero_tiebreak),
             false => {
                 a = a_tail;
                 b = b_tail;
@@ -248,19 +538,22 @@ fn cmp_ascii(mut a: &[u8], mut b: &[u8]) -> Ordering {
         }
     }
 
-    usize::cmp(&a.len(), &b.len())
+    usize::cmp(&a.len(), &b.len()).then(zero_tiebreak.unwrap_or(Ordering::Equal))
 }
 
 #[inline]
-fn cmp_digits(a: &mut &[u8], b: &mut &[u8]) -> Ordering {
-    fn trim_zeros(slice: &mut &[u8]) {
+fn cmp_digits(a: &mut &[u8], b: &mut &[u8], zero_tiebreak: &mut Option<Ordering>) -> Ordering {
+    fn trim_zeros(slice: &mut &[u8]) -> usize {
+        let mut zeros = 0;
         while let [b'0', rest @ ..] = *slice {
+            zeros += 1;
             *slice = rest
         }
+        zeros
     }
 
-    fn read_digits<'a>(slice: &mut &'a [u8]) -> &'a [u8] {
-        trim_zeros(slice);
+    fn read_digits<'a>(slice: &mut &'a [u8], zeros: &mut usize) -> &'a [u8] {
+        *zeros = trim_zeros(slice);
 
         let slice_start = slice.as_ptr();
         let mut i = 0;
@@ -275,8 +568,13 @@ fn cmp_digits(a: &mut &[u8], b: &mut &[u8]) -> Ordering {
         unsafe { core::slice::from_raw_parts(slice_start, i) }
     }
 
-    let a = read_digits(a);
-    let b = read_digits(b);
+    let mut a_zeros = 0;
+    let mut b_zeros = 0;
+    let a = read_digits(a, &mut a_zeros);
+    let b = read_digits(b, &mut b_zeros);
+    if zero_tiebreak.is_none() && a_zeros != b_zeros {
+        *zero_tiebreak = Some(a_zeros.cmp(&b_zeros));
+    }
 
     match a.len().cmp(&b.len()) {
         Ordering::Equal => {
@@ -291,9 +589,126 @@ fn cmp_digits(a: &mut &[u8], b: &mut &[u8]) -> Ordering {
     }
 }
 
+/// the decimal value of `c`, or `None` if it isn't a decimal digit
+///
+/// `char::to_digit` only recognizes ASCII `'0'..='9'`, so this additionally
+/// covers the other scripts whose decimal digits (Unicode category `Nd`)
+/// natural-sort input commonly uses; each such script is a contiguous block
+/// of ten code points starting at that script's zero
+fn unicode_digit(c: char) -> Option<u32> {
+    const DIGIT_ZEROS: &[u32] = &[
+        0x0030, // ASCII
+        0x0660, // Arabic-Indic
+        0x06F0, // Extended Arabic-Indic
+        0x07C0, // NKo
+        0x0966, // Devanagari
+        0x09E6, // Bengali
+        0x0A66, // Gurmukhi
+        0x0AE6, // Gujarati
+        0x0B66, // Oriya
+        0x0BE6, // Tamil
+        0x0C66, // Telugu
+        0x0CE6, // Kannada
+        0x0D66, // Malayalam
+        0x0E50, // Thai
+        0x0ED0, // Lao
+        0x0F20, // Tibetan
+        0xFF10, // Fullwidth
+    ];
+
+    let cp = c as u32;
+    DIGIT_ZEROS
+        .iter()
+        .find_map(|&zero| cp.checked_sub(zero).filter(|&offset| offset < 10))
+}
+
+fn cmp_unicode(a: &str, b: &str) -> Ordering {
+    let mut a_chars = a.chars();
+    let mut b_chars = b.chars();
+    // see cmp_ascii's zero_tiebreak comment: locks onto the first digit run
+    // whose zero count differs so it can't cancel out across later runs
+    let mut zero_tiebreak: Option<Ordering> = None;
+
+    loop {
+        let (a_c, b_c) = match (a_chars.clone().next(), b_chars.clone().next()) {
+            (Some(a_c), Some(b_c)) => (a_c, b_c),
+            (a_next, b_next) => {
+                return a_next
+                    .is_some()
+                    .cmp(&b_next.is_some())
+                    .then(zero_tiebreak.unwrap_or(Ordering::Equal));
+            }
+        };
+
+        let ord = match unicode_digit(a_c).is_some() && unicode_digit(b_c).is_some() {
+            true => cmp_digit_run(&mut a_chars, &mut b_chars, &mut zero_tiebreak),
+            false => {
+                a_chars.next();
+                b_chars.next();
+                a_c.cmp(&b_c)
+            }
+        };
+
+        if ord != Ordering::Equal {
+            return ord;
+        }
+    }
+}
+
+fn cmp_digit_run(
+    a: &mut core::str::Chars,
+    b: &mut core::str::Chars,
+    zero_tiebreak: &mut Option<Ordering>,
+) -> Ordering {
+    fn trim_zeros(chars: &mut core::str::Chars) -> usize {
+        let mut zeros = 0;
+        while chars.clone().next().and_then(unicode_digit) == Some(0) {
+            chars.next();
+            zeros += 1;
+        }
+        zeros
+    }
+
+    fn digit_run_len(chars: &mut core::str::Chars) -> usize {
+        let mut len = 0;
+        while chars.clone().next().and_then(unicode_digit).is_some() {
+            chars.next();
+            len += 1;
+        }
+        len
+    }
+
+    let a_zeros = trim_zeros(a);
+    let b_zeros = trim_zeros(b);
+    if zero_tiebreak.is_none() && a_zeros != b_zeros {
+        *zero_tiebreak = Some(a_zeros.cmp(&b_zeros));
+    }
+
+    let a_start = a.clone();
+    let b_start = b.clone();
+    let a_len = digit_run_len(a);
+    let b_len = digit_run_len(b);
+
+    match a_len.cmp(&b_len) {
+        Ordering::Equal => {
+            let mut a_iter = a_start;
+            let mut b_iter = b_start;
+            (0..a_len)
+                .map(|_| {
+                    let a_val = a_iter.next().and_then(unicode_digit).unwrap();
+                    let b_val = b_iter.next().and_then(unicode_digit).unwrap();
+                    a_val.cmp(&b_val)
+                })
+                .find(|&ord| ord != Ordering::Equal)
+                .unwrap_or(Ordering::Equal)
+        }
+        ord => ord,
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{Natural, NaturalSort};
+    use crate::{Natural, NaturalPath, NaturalSort, NaturalUnicode, NaturalVersion};
 
     #[test]
     fn it_works() {
@@ -328,4 +743,98 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn leading_zeros_break_ties() {
+        assert!(Natural::str("file1") < Natural::str("file01"));
+        assert!(Natural::str("file01") < Natural::str("file001"));
+        assert_ne!(Natural::str("file1"), Natural::str("file01"));
+
+        // leading-zero diffs from separate digit runs must not cancel out
+        assert_ne!(
+            Natural::str("a01b1").cmp(&Natural::str("a1b01")),
+            core::cmp::Ordering::Equal
+        );
+
+        // numerically unequal values are unaffected by the tiebreak
+        assert!(Natural::str("file9") < Natural::str("file010"));
+    }
+
+    #[test]
+    fn unicode_numerals_compare_by_value() {
+        // full-width digits: "\u{ff11}\u{ff12}" (12) vs "\u{ff19}" (9)
+        assert!(NaturalUnicode::unicode("file\u{ff11}\u{ff12}") > NaturalUnicode::unicode("file\u{ff19}"));
+
+        // arabic-indic digits: "\u{0661}\u{0660}" (10) vs "\u{0669}" (9)
+        assert!(NaturalUnicode::unicode("file\u{0661}\u{0660}") > NaturalUnicode::unicode("file\u{0669}"));
+
+        // a mixed-script run is a single numeric token, so it sorts equal to "123"
+        assert_eq!(
+            NaturalUnicode::unicode("file1\u{ff12}3").cmp(&NaturalUnicode::unicode("file123")),
+            core::cmp::Ordering::Equal
+        );
+
+        // the ascii fast path is untouched: it still compares full-width digits bytewise
+        assert_ne!(Natural::str("file\u{ff11}"), Natural::str("file1"));
+
+        // leading-zero diffs from separate digit runs must not cancel out
+        assert_ne!(
+            NaturalUnicode::unicode("a01b1").cmp(&NaturalUnicode::unicode("a1b01")),
+            core::cmp::Ordering::Equal
+        );
+
+        // Eq agrees with Ord instead of falling back to raw str equality
+        assert_eq!(
+            NaturalUnicode::unicode("file\u{ff11}"),
+            NaturalUnicode::unicode("file1")
+        );
+    }
+
+    #[test]
+    fn version_strings_sort_component_wise() {
+        assert!(NaturalVersion::version("v1.9.0") < NaturalVersion::version("v1.10.0"));
+        assert!(NaturalVersion::version("serde") < NaturalVersion::version("serde_json"));
+
+        // raw-identifier prefixes don't affect the comparison
+        assert_eq!(
+            NaturalVersion::version("r#match-1.2").cmp(&NaturalVersion::version("match-1.2")),
+            core::cmp::Ordering::Equal
+        );
+
+        // "-" and "_" are both treated as component separators
+        assert_eq!(
+            NaturalVersion::version("foo-bar").cmp(&NaturalVersion::version("foo_bar")),
+            core::cmp::Ordering::Equal
+        );
+
+        // Eq agrees with Ord instead of falling back to raw string equality
+        assert_eq!(
+            NaturalVersion::version("r#match-1.2"),
+            NaturalVersion::version("match-1.2")
+        );
+        assert_eq!(NaturalVersion::version("foo-bar"), NaturalVersion::version("foo_bar"));
+    }
+
+    #[test]
+    fn paths_sort_component_wise() {
+        use crate::natural_cmp_path;
+        use core::cmp::Ordering;
+
+        // a directory always sorts before its descendants
+        assert_eq!(natural_cmp_path(b"a", b"a/b"), Ordering::Less);
+        // even though "." < "/" byte-wise, the split makes "a.txt" sort after "a/b"
+        assert_eq!(natural_cmp_path(b"a.txt", b"a/b"), Ordering::Greater);
+
+        assert!(NaturalPath::path(b"a".as_slice()) < NaturalPath::path(b"a/b".as_slice()));
+
+        let mut paths = ["a.txt", "a", "a/b"];
+        paths.natural_sort_paths();
+        assert_eq!(paths, ["a", "a/b", "a.txt"]);
+
+        // Eq agrees with Ord instead of falling back to raw byte equality
+        assert_ne!(
+            NaturalPath::path(b"a".as_slice()),
+            NaturalPath::path(b"a/b".as_slice())
+        );
+    }
 }